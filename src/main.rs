@@ -1,29 +1,124 @@
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::process::Stdio;
 
 use futures::stream::TryStreamExt;
+use futures::StreamExt;
 
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use eyre::{eyre, Report, Result, WrapErr};
 use filetime::{self, FileTime};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use rss::Channel;
 use serde::{Deserialize, Serialize};
-use tokio::{fs, process::Command};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use tokio::{fs, io::AsyncWriteExt, process::Command};
 use tokio_stream::wrappers::ReadDirStream;
 
+const FEED_CACHE_FILE_NAME: &str = ".sound-syncer-cache.json";
+
 #[derive(Serialize, Deserialize, Debug)]
 struct Playlist {
     name: String,
     url: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum OutputFormat {
+    Mp3 { bitrate: u32 },
+    Opus { bitrate: u32 },
+    Aac { bitrate: u32 },
+    Copy,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Mp3 { bitrate: 128 }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TranscodePlan {
+    /// Remux into the destination container without touching the audio stream.
+    Remux,
+    Encode { codec: &'static str, bitrate: u32 },
+}
+
+#[derive(Debug, Clone)]
+struct ResolvedFormat {
+    extension: String,
+    plan: TranscodePlan,
+}
+
+fn extension_from_url(source_url: &str) -> String {
+    let path = source_url.split(['?', '#']).next().unwrap_or(source_url);
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|last_segment| last_segment.rsplit_once('.'))
+        .map(|(_, extension)| extension)
+        .unwrap_or("mp3");
+    let sanitized = fat32_sanitize(extension);
+    if sanitized.is_empty() {
+        "mp3".to_string()
+    } else {
+        sanitized
+    }
+}
+
+impl OutputFormat {
+    /// Resolves the format and playback speed into a concrete transcode plan, since
+    /// `Copy` can't honor a non-1.0 `playback_speed` (applying an `atempo` filter
+    /// requires re-encoding), and the chosen extension must always match whatever
+    /// codec/container the plan actually produces.
+    fn resolve(&self, playback_speed: f64, source_url: &str) -> ResolvedFormat {
+        match self {
+            OutputFormat::Mp3 { bitrate } => ResolvedFormat {
+                extension: "mp3".to_string(),
+                plan: TranscodePlan::Encode {
+                    codec: "libmp3lame",
+                    bitrate: *bitrate,
+                },
+            },
+            OutputFormat::Opus { bitrate } => ResolvedFormat {
+                extension: "opus".to_string(),
+                plan: TranscodePlan::Encode {
+                    codec: "libopus",
+                    bitrate: *bitrate,
+                },
+            },
+            OutputFormat::Aac { bitrate } => ResolvedFormat {
+                extension: "aac".to_string(),
+                plan: TranscodePlan::Encode {
+                    codec: "aac",
+                    bitrate: *bitrate,
+                },
+            },
+            OutputFormat::Copy if playback_speed == 1.0 => ResolvedFormat {
+                extension: extension_from_url(source_url),
+                plan: TranscodePlan::Remux,
+            },
+            OutputFormat::Copy => ResolvedFormat {
+                extension: "mp3".to_string(),
+                plan: TranscodePlan::Encode {
+                    codec: "libmp3lame",
+                    bitrate: 192,
+                },
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 struct Podcast {
     name: String,
     url: String,
     keep_latest: usize,
     playback_speed: f64,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -32,10 +127,114 @@ struct PodcastSet {
     podcasts: Vec<Podcast>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+struct NotifyConfig {
+    webhook_url: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct Config {
     playlists: Vec<Playlist>,
     podcasts: Vec<PodcastSet>,
+    #[serde(default)]
+    notify: Option<NotifyConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CachedFeed {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct FeedCache {
+    feeds: HashMap<String, CachedFeed>,
+}
+
+impl FeedCache {
+    async fn load(output_dir: &str) -> Result<Self> {
+        let path = format!("{output_dir}/{FEED_CACHE_FILE_NAME}");
+        match fs::read_to_string(&path).await {
+            Ok(contents) => {
+                serde_json::from_str(&contents).wrap_err_with(|| format!("Failed to parse {path}"))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("Failed to read {path}")),
+        }
+    }
+
+    async fn save(&self, output_dir: &str) -> Result<()> {
+        let path = format!("{output_dir}/{FEED_CACHE_FILE_NAME}");
+        let contents = serde_json::to_string(self).wrap_err("Failed to serialize feed cache")?;
+        fs::write(&path, contents)
+            .await
+            .wrap_err_with(|| format!("Failed to write {path}"))
+    }
+}
+
+async fn fetch_feed(
+    client: &reqwest::Client,
+    url: &str,
+    cache: &std::sync::Arc<tokio::sync::Mutex<FeedCache>>,
+) -> Result<Channel> {
+    let cached = cache.lock().await.feeds.get(url).cloned();
+
+    let mut request = client.get(url);
+    if let Some(cached) = &cached {
+        if let Some(etag) = &cached.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &cached.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = request
+        .send()
+        .await
+        .wrap_err_with(|| format!("Failed to fetch feed {url}"))?;
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        let cached = cached.ok_or_else(|| {
+            eyre!("Got 304 Not Modified for {url} but no cached body is present")
+        })?;
+        println!("Feed {url} not modified, using cached copy");
+        return Channel::read_from(cached.body.as_bytes())
+            .wrap_err_with(|| format!("Failed to parse cached feed {url}"));
+    }
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let last_modified = response
+        .headers()
+        .get(LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let body = response
+        .error_for_status()
+        .wrap_err_with(|| format!("Bad status fetching feed {url}"))?
+        .text()
+        .await
+        .wrap_err_with(|| format!("Failed to read feed body {url}"))?;
+
+    let channel =
+        Channel::read_from(body.as_bytes()).wrap_err_with(|| format!("Failed to parse feed {url}"))?;
+
+    cache.lock().await.feeds.insert(
+        url.to_string(),
+        CachedFeed {
+            etag,
+            last_modified,
+            body,
+        },
+    );
+
+    Ok(channel)
 }
 
 #[derive(Parser, Debug)]
@@ -45,6 +244,104 @@ struct Args {
     config: String,
     #[arg(short, long)]
     output_dir: String,
+    /// Maximum number of playlists, of podcast sets, and of episodes within each podcast set to
+    /// sync concurrently; the limit applies to each level separately, not to their total.
+    #[arg(short, long, default_value_t = 4, value_parser = clap::value_parser!(usize).range(1..))]
+    jobs: usize,
+    /// Path to write a JSON summary of what was downloaded, skipped, and failed.
+    #[arg(long)]
+    report: Option<String>,
+    /// Run as a daemon, re-syncing on this interval (e.g. "1h", "30m") instead of exiting after one run.
+    #[arg(long)]
+    interval: Option<humantime::Duration>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum RecordStatus {
+    Ok,
+    Skipped { reason: String },
+    Failed { error: String },
+}
+
+#[derive(Serialize, Debug)]
+struct OutcomeRecord {
+    name: String,
+    #[serde(flatten)]
+    status: RecordStatus,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct SyncOutcome {
+    records: Vec<OutcomeRecord>,
+}
+
+impl SyncOutcome {
+    fn record_ok(&mut self, name: impl Into<String>) {
+        self.records.push(OutcomeRecord {
+            name: name.into(),
+            status: RecordStatus::Ok,
+        });
+    }
+
+    fn record_skipped(&mut self, name: impl Into<String>, reason: impl Into<String>) {
+        self.records.push(OutcomeRecord {
+            name: name.into(),
+            status: RecordStatus::Skipped {
+                reason: reason.into(),
+            },
+        });
+    }
+
+    fn record_failed(&mut self, name: impl Into<String>, error: &Report) {
+        self.records.push(OutcomeRecord {
+            name: name.into(),
+            status: RecordStatus::Failed {
+                error: format!("{error:#}"),
+            },
+        });
+    }
+
+    fn has_failures(&self) -> bool {
+        self.records
+            .iter()
+            .any(|record| matches!(record.status, RecordStatus::Failed { .. }))
+    }
+
+    fn print_summary(&self) {
+        let ok = self
+            .records
+            .iter()
+            .filter(|r| matches!(r.status, RecordStatus::Ok))
+            .count();
+        let skipped = self
+            .records
+            .iter()
+            .filter(|r| matches!(r.status, RecordStatus::Skipped { .. }))
+            .count();
+        let failed: Vec<&OutcomeRecord> = self
+            .records
+            .iter()
+            .filter(|r| matches!(r.status, RecordStatus::Failed { .. }))
+            .collect();
+
+        println!(
+            "Sync summary: {ok} ok, {skipped} skipped, {0} failed",
+            failed.len()
+        );
+        for record in failed {
+            if let RecordStatus::Failed { error } = &record.status {
+                println!("- {0}: {error}", record.name);
+            }
+        }
+    }
+
+    async fn save(&self, path: &str) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self).wrap_err("Failed to serialize report")?;
+        fs::write(path, contents)
+            .await
+            .wrap_err_with(|| format!("Failed to write report to {path}"))
+    }
 }
 
 async fn create_and_get_dir(output_dir: &str, name: &str) -> Result<String> {
@@ -78,6 +375,50 @@ async fn sync_playlist(playlist: &Playlist, output_dir: &str) -> Result<()> {
     }
 }
 
+async fn download_with_progress(url: &str, dest: &Path, multi_progress: &MultiProgress) -> Result<()> {
+    let response = reqwest::get(url)
+        .await
+        .wrap_err_with(|| format!("Failed to fetch {url}"))?
+        .error_for_status()
+        .wrap_err_with(|| format!("Bad status fetching {url}"))?;
+
+    let progress = match response.content_length() {
+        Some(total) => {
+            let bar = ProgressBar::new(total);
+            bar.set_style(
+                ProgressStyle::with_template(
+                    "{bar:40.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})",
+                )
+                .wrap_err("Failed to build progress bar style")?,
+            );
+            bar
+        }
+        None => {
+            let bar = ProgressBar::new_spinner();
+            bar.set_style(
+                ProgressStyle::with_template("{spinner} {bytes} downloaded ({bytes_per_sec})")
+                    .wrap_err("Failed to build progress bar style")?,
+            );
+            bar
+        }
+    };
+    let progress = multi_progress.add(progress);
+
+    let mut file = fs::File::create(dest)
+        .await
+        .wrap_err_with(|| format!("Failed to create {dest:?}"))?;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.wrap_err_with(|| format!("Failed to read chunk from {url}"))?;
+        file.write_all(&chunk)
+            .await
+            .wrap_err_with(|| format!("Failed to write chunk to {dest:?}"))?;
+        progress.inc(chunk.len() as u64);
+    }
+    progress.finish_and_clear();
+    Ok(())
+}
+
 fn fat32_sanitize(f: &str) -> String {
     f.chars()
         .filter(|c| match c {
@@ -87,15 +428,154 @@ fn fat32_sanitize(f: &str) -> String {
         .collect()
 }
 
-fn podcast_file_name(podcast: &str, title: &str) -> String {
+fn podcast_file_name(podcast: &str, title: &str, extension: &str) -> String {
     format!(
-        "{} - {}.mp3",
+        "{} - {}.{}",
         fat32_sanitize(podcast),
-        fat32_sanitize(title)
+        fat32_sanitize(title),
+        fat32_sanitize(extension)
     )
 }
 
-async fn sync_podcasts(podcasts: &PodcastSet, output_dir: &str) -> Result<()> {
+struct EpisodeMetadata {
+    title: String,
+    album: String,
+    episode_number: Option<i64>,
+    description: Option<String>,
+    image_url: Option<String>,
+}
+
+struct PendingEpisode {
+    name: String,
+    file_name: String,
+    url: String,
+    pub_time: DateTime<chrono::FixedOffset>,
+    playback_speed: f64,
+    format: ResolvedFormat,
+    metadata: EpisodeMetadata,
+}
+
+async fn download_cover_art(tempdir: &Path, file_name: &str, image_url: &str) -> Result<std::path::PathBuf> {
+    let bytes = reqwest::get(image_url)
+        .await
+        .wrap_err_with(|| format!("Failed to fetch cover art from {image_url}"))?
+        .error_for_status()
+        .wrap_err_with(|| format!("Bad status fetching cover art from {image_url}"))?
+        .bytes()
+        .await
+        .wrap_err_with(|| format!("Failed to read cover art from {image_url}"))?;
+    let cover_path = tempdir.join(format!("{file_name}.cover"));
+    fs::write(&cover_path, &bytes)
+        .await
+        .wrap_err_with(|| format!("Failed to write cover art to {cover_path:?}"))?;
+    Ok(cover_path)
+}
+
+async fn process_episode(
+    tempdir: &Path,
+    podcast_dir: &str,
+    episode: PendingEpisode,
+    multi_progress: &MultiProgress,
+) -> Result<()> {
+    let PendingEpisode {
+        name: _,
+        file_name,
+        url,
+        pub_time,
+        playback_speed,
+        format,
+        metadata,
+    } = episode;
+    println!("Downloading {file_name} from {url}");
+    let temp_file = tempdir.join(&file_name);
+    download_with_progress(&url, &temp_file, multi_progress)
+        .await
+        .wrap_err("Failed to download episode")?;
+
+    let cover_path = match &metadata.image_url {
+        Some(image_url) => match download_cover_art(tempdir, &file_name, image_url).await {
+            Ok(path) => Some(path),
+            Err(e) => {
+                println!("Failed to download cover art for {file_name}: {e:#}");
+                None
+            }
+        },
+        None => None,
+    };
+
+    let path = format!("{podcast_dir}/{file_name}");
+
+    let mut command = tokio::process::Command::new("ffmpeg");
+    command.arg("-i").arg(&temp_file);
+    if let Some(cover_path) = &cover_path {
+        command.arg("-i").arg(cover_path);
+    }
+    match format.plan {
+        TranscodePlan::Remux => {
+            println!("Remuxing {file_name} without re-encoding");
+            command.arg("-c").arg("copy");
+        }
+        TranscodePlan::Encode { codec, bitrate } => {
+            if playback_speed != 1.0 {
+                println!("Adjusting playback speed");
+                command.arg("-filter:a").arg(format!("atempo={playback_speed}"));
+            }
+            command
+                .arg("-codec:a")
+                .arg(codec)
+                .arg("-b:a")
+                .arg(format!("{bitrate}k"));
+        }
+    }
+
+    command
+        .arg("-metadata")
+        .arg(format!("title={}", metadata.title))
+        .arg("-metadata")
+        .arg(format!("artist={}", metadata.album))
+        .arg("-metadata")
+        .arg(format!("album={}", metadata.album))
+        .arg("-metadata")
+        .arg(format!("date={}", pub_time.format("%Y-%m-%d")));
+    if let Some(episode_number) = metadata.episode_number {
+        command
+            .arg("-metadata")
+            .arg(format!("track={episode_number}"));
+    }
+    if let Some(description) = &metadata.description {
+        command.arg("-metadata").arg(format!("comment={description}"));
+    }
+    if cover_path.is_some() {
+        command
+            .arg("-map")
+            .arg("0:a")
+            .arg("-map")
+            .arg("1:0")
+            .arg("-c:v")
+            .arg("copy")
+            .arg("-disposition:v")
+            .arg("attached_pic");
+    }
+
+    command
+        .arg(&path)
+        .stdin(Stdio::null())
+        .status()
+        .await
+        .wrap_err("Failed to run ffmpeg on episode")?;
+    filetime::set_file_mtime(&path, FileTime::from_unix_time(pub_time.timestamp(), 0))
+        .wrap_err("Failed to set mtime")?;
+    Ok(())
+}
+
+async fn sync_podcasts(
+    podcasts: &PodcastSet,
+    output_dir: &str,
+    cache: &std::sync::Arc<tokio::sync::Mutex<FeedCache>>,
+    jobs: usize,
+    outcome: &std::sync::Arc<tokio::sync::Mutex<SyncOutcome>>,
+    multi_progress: &MultiProgress,
+) -> Result<()> {
     let podcast_dir = create_and_get_dir(output_dir, &podcasts.name).await?;
     println!("Processing podcast set {0}", podcasts.name);
     let read_dir = fs::read_dir(&podcast_dir)
@@ -131,48 +611,85 @@ async fn sync_podcasts(podcasts: &PodcastSet, output_dir: &str) -> Result<()> {
         println!("- {name}: {size} bytes");
     });
     let mut expected_files = HashSet::<String>::new();
+    let mut pending_episodes = Vec::<PendingEpisode>::new();
 
     let tempdir = tempfile::tempdir().wrap_err("Failed to create temp dir")?;
+    let client = reqwest::Client::new();
 
     for podcast in &podcasts.podcasts {
         println!("Fetching {0}", podcast.name);
-        let content = reqwest::get(&podcast.url)
-            .await
-            .wrap_err_with(|| {
-                format!(
+        let channel = fetch_feed(&client, &podcast.url, cache).await;
+        let channel = match channel {
+            Ok(channel) => channel,
+            Err(e) => {
+                let e = e.wrap_err(format!(
                     "Failed to fetch podcast {0} from {1}",
                     podcast.name, podcast.url
-                )
-            })?
-            .bytes()
-            .await?;
-        let channel = Channel::read_from(&content[..])?;
+                ));
+                outcome
+                    .lock()
+                    .await
+                    .record_failed(format!("podcast:{0}", podcast.name), &e);
+                continue;
+            }
+        };
+        let channel_title = channel.title().to_string();
+        let channel_image = channel
+            .itunes_ext()
+            .and_then(|ext| ext.image())
+            .map(String::from)
+            .or_else(|| channel.image().map(|image| image.url().to_string()));
+
         for item in channel.items.iter().take(podcast.keep_latest) {
-            let title = item
-                .title
-                .clone()
-                .ok_or_else(|| eyre!("Podcast {0} item doesn't have title", podcast.name))?;
-            let pub_date_str = item.pub_date.clone().ok_or_else(|| {
-                eyre!(
-                    "Podcast {0} episode {title} doesn't have pub date",
-                    podcast.name
-                )
-            })?;
-            let pub_time = DateTime::parse_from_rfc2822(pub_date_str.as_str())
-                .wrap_err_with(|| format!("Failed to parse date time {pub_date_str}"))?;
-            let enclosure = item.enclosure.clone().ok_or_else(|| {
-                eyre!(
-                    "Podcast {0} episode {title} doesn't have enclosure",
-                    podcast.name
-                )
-            })?;
-            let file_name = podcast_file_name(podcast.name.as_str(), title.as_str());
+            let parsed = (|| -> Result<(String, DateTime<chrono::FixedOffset>, rss::Enclosure)> {
+                let title = item
+                    .title
+                    .clone()
+                    .ok_or_else(|| eyre!("Podcast {0} item doesn't have title", podcast.name))?;
+                let pub_date_str = item.pub_date.clone().ok_or_else(|| {
+                    eyre!(
+                        "Podcast {0} episode {title} doesn't have pub date",
+                        podcast.name
+                    )
+                })?;
+                let pub_time = DateTime::parse_from_rfc2822(pub_date_str.as_str())
+                    .wrap_err_with(|| format!("Failed to parse date time {pub_date_str}"))?;
+                let enclosure = item.enclosure.clone().ok_or_else(|| {
+                    eyre!(
+                        "Podcast {0} episode {title} doesn't have enclosure",
+                        podcast.name
+                    )
+                })?;
+                Ok((title, pub_time, enclosure))
+            })();
+
+            let (title, pub_time, enclosure) = match parsed {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    outcome
+                        .lock()
+                        .await
+                        .record_failed(format!("episode:{0}/<unknown>", podcast.name), &e);
+                    continue;
+                }
+            };
+
+            let resolved_format = podcast
+                .format
+                .resolve(podcast.playback_speed, &enclosure.url);
+            let file_name =
+                podcast_file_name(podcast.name.as_str(), title.as_str(), &resolved_format.extension);
+            let episode_name = format!("episode:{0}/{title}", podcast.name);
             let redownload = match existing_files.get(&file_name) {
                 Some(mtime) => {
                     if mtime.timestamp() == pub_time.timestamp() {
                         println!(
                             "Not redownloading {file_name} as it has the expected mtime ({pub_time})"
                         );
+                        outcome.lock().await.record_skipped(
+                            episode_name.clone(),
+                            "already downloaded with the expected mtime",
+                        );
                         false
                     } else {
                         println!("Redownloading {file_name} as it has the wrong mtime ({mtime}, expected {pub_time})");
@@ -186,39 +703,164 @@ async fn sync_podcasts(podcasts: &PodcastSet, output_dir: &str) -> Result<()> {
             };
 
             if redownload {
-                let url = enclosure.url;
-                println!("Downloading {file_name} from {url}");
-                let temp_file = tempdir.path().join(&file_name);
-                tokio::process::Command::new("curl")
-                    .arg("-L")
-                    .arg(url)
-                    .arg("-o")
-                    .arg(&temp_file)
-                    .stdin(Stdio::null())
-                    .status()
-                    .await
-                    .wrap_err("Failed to download episode")?;
-                println!("Adjusting playback speed");
-                let path = format!("{podcast_dir}/{file_name}");
-                tokio::process::Command::new("ffmpeg")
-                    .arg("-i")
-                    .arg(&temp_file)
-                    .arg("-filter:a")
-                    .arg(format!("atempo={0}", podcast.playback_speed))
-                    .arg(&path)
-                    .stdin(Stdio::null())
-                    .status()
-                    .await
-                    .wrap_err("Failed to download episode")?;
-                filetime::set_file_mtime(&path, FileTime::from_unix_time(pub_time.timestamp(), 0))
-                    .wrap_err("Failed to set mtime")?;
+                let episode_number = item
+                    .itunes_ext()
+                    .and_then(|ext| ext.episode())
+                    .and_then(|episode| episode.parse().ok());
+                let description = item
+                    .itunes_ext()
+                    .and_then(|ext| ext.summary())
+                    .map(String::from)
+                    .or_else(|| item.description().map(String::from));
+                let image_url = item
+                    .itunes_ext()
+                    .and_then(|ext| ext.image())
+                    .map(String::from)
+                    .or_else(|| channel_image.clone());
+
+                pending_episodes.push(PendingEpisode {
+                    name: episode_name,
+                    file_name: file_name.clone(),
+                    url: enclosure.url,
+                    pub_time,
+                    playback_speed: podcast.playback_speed,
+                    metadata: EpisodeMetadata {
+                        title: title.clone(),
+                        album: channel_title.clone(),
+                        episode_number,
+                        description,
+                        image_url,
+                    },
+                    format: resolved_format,
+                });
             }
             expected_files.insert(file_name.clone());
         }
     }
+
+    futures::stream::iter(pending_episodes.into_iter().map(|episode| {
+        let tempdir = tempdir.path();
+        let podcast_dir = &podcast_dir;
+        async move {
+            let name = episode.name.clone();
+            match process_episode(tempdir, podcast_dir, episode, multi_progress).await {
+                Ok(()) => outcome.lock().await.record_ok(name),
+                Err(e) => outcome.lock().await.record_failed(name, &e),
+            }
+        }
+    }))
+    .buffer_unordered(jobs)
+    .collect::<Vec<()>>()
+    .await;
+
     Ok(())
 }
 
+async fn notify_failures(
+    client: &reqwest::Client,
+    webhook_url: &str,
+    outcome: &SyncOutcome,
+) -> Result<()> {
+    let message = outcome
+        .records
+        .iter()
+        .filter_map(|record| match &record.status {
+            RecordStatus::Failed { error } => Some(format!("{0}: {error}", record.name)),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if message.is_empty() {
+        return Ok(());
+    }
+
+    client
+        .post(webhook_url)
+        .json(&serde_json::json!({ "text": message }))
+        .send()
+        .await
+        .wrap_err_with(|| format!("Failed to notify webhook {webhook_url}"))?
+        .error_for_status()
+        .wrap_err_with(|| format!("Webhook {webhook_url} returned an error status"))?;
+    Ok(())
+}
+
+async fn report_and_notify(
+    outcome: &SyncOutcome,
+    report_path: Option<&str>,
+    http_client: &reqwest::Client,
+    notify: Option<&NotifyConfig>,
+) -> Result<()> {
+    outcome.print_summary();
+    if let Some(report_path) = report_path {
+        outcome.save(report_path).await?;
+    }
+    if outcome.has_failures() {
+        if let Some(notify) = notify {
+            if let Err(e) = notify_failures(http_client, &notify.webhook_url, outcome).await {
+                eprintln!("Failed to send failure notification: {e:#}");
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn run_cycle(
+    config: &Config,
+    output_dir: &str,
+    jobs: usize,
+    feed_cache: &std::sync::Arc<tokio::sync::Mutex<FeedCache>>,
+) -> Result<SyncOutcome> {
+    let outcome = std::sync::Arc::new(tokio::sync::Mutex::new(SyncOutcome::default()));
+    let multi_progress = MultiProgress::new();
+
+    futures::stream::iter(config.playlists.iter().map(|playlist| {
+        let outcome = &outcome;
+        async move {
+            let name = format!("playlist:{0}", playlist.name);
+            match sync_playlist(playlist, output_dir).await {
+                Ok(()) => outcome.lock().await.record_ok(name),
+                Err(e) => outcome.lock().await.record_failed(name, &e),
+            }
+        }
+    }))
+    .buffer_unordered(jobs)
+    .collect::<Vec<()>>()
+    .await;
+
+    futures::stream::iter(config.podcasts.iter().map(|podcast_set| {
+        let outcome = &outcome;
+        let multi_progress = &multi_progress;
+        async move {
+            match sync_podcasts(
+                podcast_set,
+                output_dir,
+                feed_cache,
+                jobs,
+                &outcome,
+                multi_progress,
+            )
+            .await
+            {
+                Ok(()) => {}
+                Err(e) => outcome
+                    .lock()
+                    .await
+                    .record_failed(format!("podcast_set:{0}", podcast_set.name), &e),
+            }
+        }
+    }))
+    .buffer_unordered(jobs)
+    .collect::<Vec<()>>()
+    .await;
+
+    feed_cache.lock().await.save(output_dir).await?;
+
+    Ok(std::sync::Arc::into_inner(outcome)
+        .expect("outcome should have no other references left")
+        .into_inner())
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
@@ -231,11 +873,53 @@ async fn main() -> Result<()> {
 
     println!("Loaded config: {config:?}");
 
-    for playlist in &config.playlists {
-        sync_playlist(playlist, args.output_dir.as_str()).await?;
-    }
-    for podcast_set in &config.podcasts {
-        sync_podcasts(&podcast_set, args.output_dir.as_str()).await?;
+    let feed_cache = std::sync::Arc::new(tokio::sync::Mutex::new(
+        FeedCache::load(args.output_dir.as_str()).await?,
+    ));
+    let http_client = reqwest::Client::new();
+    let output_dir = args.output_dir.as_str();
+
+    match args.interval {
+        Some(interval) => {
+            if interval.is_zero() {
+                return Err(eyre!("--interval must be greater than zero"));
+            }
+            let mut ticker = tokio::time::interval(*interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let outcome = match run_cycle(&config, output_dir, args.jobs, &feed_cache).await {
+                    Ok(outcome) => outcome,
+                    Err(e) => {
+                        eprintln!("Sync cycle failed: {e:#}");
+                        continue;
+                    }
+                };
+                if let Err(e) = report_and_notify(
+                    &outcome,
+                    args.report.as_deref(),
+                    &http_client,
+                    config.notify.as_ref(),
+                )
+                .await
+                {
+                    eprintln!("Failed to report sync cycle results: {e:#}");
+                }
+            }
+        }
+        None => {
+            let outcome = run_cycle(&config, output_dir, args.jobs, &feed_cache).await?;
+            report_and_notify(
+                &outcome,
+                args.report.as_deref(),
+                &http_client,
+                config.notify.as_ref(),
+            )
+            .await?;
+            if outcome.has_failures() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
     }
-    Ok(())
 }